@@ -1,4 +1,9 @@
-use noether::lattice::{BooleanAlgebra, JoinSemiLattice, MeetSemiLattice};
+use noether::connection::Connection;
+use noether::lattice::{
+    BooleanAlgebra, CompleteLattice, Dual, HeytingAlgebra, JoinSemiLattice, MeetSemiLattice,
+};
+use noether::ring::BooleanRing;
+use noether::solver::fixpoint;
 use noether::{LowerBounded, SymmetricDifference, UpperBounded};
 use std::cmp::Ordering;
 use std::fmt::{self, Debug, Display};
@@ -130,9 +135,49 @@ fn main() {
     let rhs = a.join(&b).meet(&a.join(&c));
     assert_eq!(lhs, rhs);
 
+    // Heyting-algebra structure, picked up for free from the blanket impl
+    // for `BooleanAlgebra`: a ⇒ b is the relative pseudocomplement, and
+    // `neg` agrees with `complement` on a Boolean algebra.
+    println!("a ⇒ b = {}", a.implies(&b));
+    println!("¬a (Heyting) = {}", a.neg());
+
     // Type-level assertions (compile-time checks by trait bounds)
     fn _assert_boolean_algebra<T: BooleanAlgebra>() {}
     _assert_boolean_algebra::<P5>();
 
-    println!("All lattice examples succeeded.");
+    // Fixpoint solver: node 0 is entry, 0 -> {1, 2} -> 3, each node's
+    // transfer function adds its own index to the running state.
+    let entry: P5 = [0].into_iter().collect();
+    let graph = vec![vec![1, 2], vec![3], vec![3], vec![]];
+    let states = fixpoint(&graph, entry, |node, in_state| {
+        let gen: P5 = std::iter::once(node).collect();
+        in_state.join(&gen)
+    });
+    println!("fixpoint states = {states:?}");
+
+    // CompleteLattice: join_all/meet_all fold a whole batch of powersets at once.
+    println!("join_all([a, b, c]) = {}", P5::join_all([a, b, c]));
+    println!("meet_all([a, b, c]) = {}", P5::meet_all([a, b, c]));
+
+    // Dual<Powerset<N>> is itself a Boolean algebra, with join/meet and ⊥/⊤
+    // swapped relative to the underlying type.
+    let da = Dual(a);
+    let db = Dual(b);
+    println!("Dual(a) ∨ Dual(b) = Dual({})", da.join(&db).0);
+
+    // A concrete Galois connection on Powerset<5>: fixing m, `lower(a) = a ∧ m`
+    // and `upper(b) = m ⇒ b` form an adjoint pair (this is exactly the
+    // adjunction behind relative pseudocomplementation).
+    let m: P5 = [1, 2, 3].into_iter().collect();
+    let conn: Connection<P5, P5> =
+        Connection::new(move |x: &P5| x.meet(&m), move |y: &P5| m.implies(y));
+    println!("lower(a) = {}, upper(b) = {}", conn.lower(&a), conn.upper(&b));
+
+    // Boolean ring structure, picked up for free from the blanket impl for
+    // `BooleanAlgebra + SymmetricDifference`: Δ as addition, ∩ as
+    // multiplication.
+    println!("a + b (ring) = {}", a.add(&b));
+    println!("a · b (ring) = {}", a.mul(&b));
+
+    println!("All lattice examples ran.");
 }
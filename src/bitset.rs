@@ -0,0 +1,233 @@
+//! Arbitrary-width bitset powerset lattice.
+//!
+//! The `Powerset<N>` example is capped at `N ≤ 64` by its single `u64` mask.
+//! `BitSetLattice<N>` lifts the same structure to arbitrary `N`, backed by a
+//! boxed slice of `u64` words, with word-parallel union/intersection/
+//! complement so large finite powersets work efficiently.
+use crate::lattice::{BooleanAlgebra, JoinSemiLattice, MeetSemiLattice};
+use crate::{LowerBounded, SymmetricDifference, UpperBounded};
+use std::cmp::Ordering;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+const fn word_count(n: usize) -> usize {
+    n.div_ceil(WORD_BITS)
+}
+
+/// The powerset of `{0, ..., N - 1}`, ordered by ⊆, backed by `⌈N / 64⌉`
+/// `u64` words rather than a single machine word.
+#[derive(Debug, Clone, Eq, PartialEq, Ord)]
+pub struct BitSetLattice<const N: usize> {
+    words: Box<[u64]>,
+}
+
+impl<const N: usize> BitSetLattice<N> {
+    /// The empty set.
+    pub fn empty() -> Self {
+        Self {
+            words: vec![0u64; word_count(N)].into_boxed_slice(),
+        }
+    }
+
+    /// Clear any bits at index ≥ N in the last word, restoring the invariant
+    /// that a set is always a subset of `{0, ..., N - 1}`.
+    fn mask_last_word(&mut self) {
+        let rem = N % WORD_BITS;
+        if rem != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1u64 << rem) - 1;
+            }
+        }
+    }
+
+    /// Insert `index` into the set.
+    pub fn insert(&mut self, index: usize) {
+        assert!(index < N, "index {index} out of range for N = {N}");
+        self.words[index / WORD_BITS] |= 1 << (index % WORD_BITS);
+    }
+
+    /// Remove `index` from the set.
+    pub fn remove(&mut self, index: usize) {
+        assert!(index < N, "index {index} out of range for N = {N}");
+        self.words[index / WORD_BITS] &= !(1 << (index % WORD_BITS));
+    }
+
+    /// Whether `index` is a member of the set.
+    pub fn contains(&self, index: usize) -> bool {
+        index < N && (self.words[index / WORD_BITS] >> (index % WORD_BITS)) & 1 != 0
+    }
+
+    /// Number of elements in the set.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Iterate over the indices that are set, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            (0..WORD_BITS)
+                .filter(move |b| (word >> b) & 1 != 0)
+                .map(move |b| w * WORD_BITS + b)
+        })
+    }
+}
+
+impl<const N: usize> FromIterator<usize> for BitSetLattice<N> {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut set = Self::empty();
+        for index in iter {
+            set.insert(index);
+        }
+        set
+    }
+}
+
+impl<const N: usize> PartialOrd for BitSetLattice<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let mut le = true;
+        let mut ge = true;
+        for (a, b) in self.words.iter().zip(&other.words) {
+            let meet = a & b;
+            le &= *a == meet;
+            ge &= *b == meet;
+        }
+        match (le, ge) {
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(Ordering::Less),
+            (false, true) => Some(Ordering::Greater),
+            (false, false) => None,
+        }
+    }
+}
+
+impl<const N: usize> JoinSemiLattice for BitSetLattice<N> {
+    fn join(&self, other: &Self) -> Self {
+        Self {
+            words: self.words.iter().zip(&other.words).map(|(a, b)| a | b).collect(),
+        }
+    }
+}
+
+impl<const N: usize> MeetSemiLattice for BitSetLattice<N> {
+    fn meet(&self, other: &Self) -> Self {
+        Self {
+            words: self.words.iter().zip(&other.words).map(|(a, b)| a & b).collect(),
+        }
+    }
+}
+
+impl<const N: usize> LowerBounded for BitSetLattice<N> {
+    fn infimum() -> Self {
+        Self::empty()
+    }
+}
+
+impl<const N: usize> UpperBounded for BitSetLattice<N> {
+    fn supremum() -> Self {
+        let mut set = Self {
+            words: vec![!0u64; word_count(N)].into_boxed_slice(),
+        };
+        set.mask_last_word();
+        set
+    }
+}
+
+impl<const N: usize> BooleanAlgebra for BitSetLattice<N> {
+    fn complement(&self) -> Self {
+        let mut set = Self {
+            words: self.words.iter().map(|w| !w).collect(),
+        };
+        set.mask_last_word();
+        set
+    }
+}
+
+impl<const N: usize> SymmetricDifference for BitSetLattice<N> {
+    fn sym_diff(&self, other: &Self) -> Self {
+        Self {
+            words: self.words.iter().zip(&other.words).map(|(a, b)| a ^ b).collect(),
+        }
+    }
+}
+
+/// A gen/kill transfer set: the canonical monotone transfer function for
+/// forward dataflow over a powerset domain, as used by MIR-style dataflow
+/// analyses. Applying it computes `(state \ kill) ∪ gen`.
+#[derive(Debug, Clone)]
+pub struct GenKill<const N: usize> {
+    pub gen: BitSetLattice<N>,
+    pub kill: BitSetLattice<N>,
+}
+
+impl<const N: usize> GenKill<N> {
+    /// Build a transfer set from its gen and kill sets.
+    pub fn new(gen: BitSetLattice<N>, kill: BitSetLattice<N>) -> Self {
+        Self { gen, kill }
+    }
+
+    /// Apply the transfer set to `state` in place: `state = (state \ kill) ∪ gen`.
+    pub fn apply(&self, state: &mut BitSetLattice<N>) {
+        for ((word, kill_word), gen_word) in
+            state.words.iter_mut().zip(&self.kill.words).zip(&self.gen.words)
+        {
+            *word = (*word & !kill_word) | gen_word;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Exercise N well past the 64-bit word boundary.
+    type Set200 = BitSetLattice<200>;
+
+    #[test]
+    fn boolean_algebra_laws_beyond_64_bits() {
+        let a: Set200 = [0, 63, 64, 127, 128, 199].into_iter().collect();
+        let b: Set200 = [1, 64, 65, 128, 150].into_iter().collect();
+
+        assert_eq!(a.join(&a.complement()), Set200::supremum());
+        assert_eq!(a.meet(&a.complement()), Set200::infimum());
+        assert_eq!(a.complement().complement(), a);
+
+        // De Morgan's laws.
+        assert_eq!(a.join(&b).complement(), a.complement().meet(&b.complement()));
+        assert_eq!(a.meet(&b).complement(), a.complement().join(&b.complement()));
+
+        assert_eq!(Set200::supremum().count_ones(), 200);
+        assert_eq!(Set200::infimum().count_ones(), 0);
+    }
+
+    #[test]
+    fn membership_and_iteration() {
+        let indices = [0, 5, 64, 199];
+        let set: Set200 = indices.into_iter().collect();
+        for i in indices {
+            assert!(set.contains(i));
+        }
+        assert!(!set.contains(6));
+        assert_eq!(set.count_ones(), indices.len());
+        assert_eq!(set.iter().collect::<Vec<_>>(), indices);
+    }
+
+    #[test]
+    fn gen_kill_apply_is_state_minus_kill_union_gen() {
+        let mut state: Set200 = [1, 2, 3, 199].into_iter().collect();
+        let gen_kill = GenKill::new(
+            [4, 100].into_iter().collect(),
+            [2, 199].into_iter().collect(),
+        );
+        gen_kill.apply(&mut state);
+        assert_eq!(state, [1, 3, 4, 100].into_iter().collect());
+    }
+
+    #[test]
+    fn subset_order_matches_membership() {
+        let a: Set200 = [1, 2].into_iter().collect();
+        let b: Set200 = [1, 2, 3].into_iter().collect();
+        let c: Set200 = [1, 4].into_iter().collect();
+        assert!(a < b);
+        assert!(a.partial_cmp(&c).is_none());
+    }
+}
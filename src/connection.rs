@@ -0,0 +1,192 @@
+//! Galois connections (monotone adjoint pairs) between posets.
+//!
+//! A Galois connection between posets `A` and `B` is a pair of monotone maps
+//! `lower: A → B` (the left adjoint) and `upper: B → A` (the right adjoint)
+//! satisfying the adjunction `lower(a) ≤ b ⟺ a ≤ upper(b)`. They let
+//! structure proved on one side of the connection (e.g. a fixed point, a
+//! closure operator) transport to the other.
+use std::rc::Rc;
+
+/// A monotone Galois connection (adjoint pair) between posets `A` and `B`.
+///
+/// # Mathematical Definition
+/// `lower: A → B` and `upper: B → A` form a Galois connection if, for all
+/// `a ∈ A, b ∈ B`:
+///
+/// lower(a) ≤ b ⟺ a ≤ upper(b)
+///
+/// `lower` is the left adjoint ("floor into B") and `upper` is the right
+/// adjoint ("ceiling into A"). Both are automatically monotone, and the
+/// round-trip inequalities `a ≤ upper(lower(a))` and `lower(upper(b)) ≤ b`
+/// always hold.
+pub struct Connection<A, B> {
+    lower: Rc<dyn Fn(&A) -> B>,
+    upper: Rc<dyn Fn(&B) -> A>,
+}
+
+impl<A, B> Connection<A, B>
+where
+    A: PartialOrd,
+    B: PartialOrd,
+{
+    /// Build a connection from its left and right adjoints. Callers are
+    /// responsible for the adjoints actually satisfying the adjunction;
+    /// [`Connection::check_adjunction`] can spot-check it on sample inputs.
+    pub fn new(lower: impl Fn(&A) -> B + 'static, upper: impl Fn(&B) -> A + 'static) -> Self {
+        Self {
+            lower: Rc::new(lower),
+            upper: Rc::new(upper),
+        }
+    }
+
+    /// The left adjoint, `lower(a)`.
+    ///
+    /// In debug builds this checks the round-trip law `a ≤ upper(lower(a))`
+    /// (a consequence of the defining adjunction) on the sampled input.
+    pub fn lower(&self, a: &A) -> B {
+        let b = (self.lower)(a);
+        debug_assert!(
+            *a <= (self.upper)(&b),
+            "Galois connection violated: expected a ≤ upper(lower(a))"
+        );
+        b
+    }
+
+    /// The right adjoint, `upper(b)`.
+    ///
+    /// In debug builds this checks the round-trip law `lower(upper(b)) ≤ b`
+    /// (a consequence of the defining adjunction) on the sampled input.
+    pub fn upper(&self, b: &B) -> A {
+        let a = (self.upper)(b);
+        debug_assert!(
+            (self.lower)(&a) <= *b,
+            "Galois connection violated: expected lower(upper(b)) ≤ b"
+        );
+        a
+    }
+
+    /// `a ≤ upper(lower(a))`: one half of the round-trip inequalities that
+    /// hold for any Galois connection.
+    pub fn round_trip_a(&self, a: &A) -> bool {
+        *a <= self.upper(&self.lower(a))
+    }
+
+    /// `lower(upper(b)) ≤ b`: the other half of the round-trip inequalities.
+    pub fn round_trip_b(&self, b: &B) -> bool {
+        self.lower(&self.upper(b)) <= *b
+    }
+
+    /// Spot-check the defining adjunction `lower(a) ≤ b ⟺ a ≤ upper(b)` for
+    /// one sample pair `(a, b)`.
+    pub fn check_adjunction(&self, a: &A, b: &B) -> bool {
+        (self.lower(a) <= *b) == (*a <= self.upper(b))
+    }
+
+    /// The induced closure operator `upper ∘ lower : A → A`. It is idempotent
+    /// and monotone for any Galois connection.
+    pub fn closure(&self, a: &A) -> A {
+        self.upper(&self.lower(a))
+    }
+
+    /// Flip the connection: the dual of `A ↔ B` is `Dual<B> ↔ Dual<A>` with
+    /// the adjoints swapped. Both orders are reversed, which is what keeps
+    /// the adjunction (and the round-trip checks) true for the flipped pair.
+    pub fn dual(self) -> Connection<crate::lattice::Dual<B>, crate::lattice::Dual<A>>
+    where
+        A: 'static,
+        B: 'static,
+    {
+        use crate::lattice::Dual;
+        let Connection { lower, upper } = self;
+        Connection::new(
+            move |b: &Dual<B>| Dual(upper(&b.0)),
+            move |a: &Dual<A>| Dual(lower(&a.0)),
+        )
+    }
+
+    /// Compose `self: A ↔ B` with `other: B ↔ C` into a connection `A ↔ C`.
+    pub fn compose<C>(self, other: Connection<B, C>) -> Connection<A, C>
+    where
+        A: 'static,
+        B: 'static,
+        C: PartialOrd + 'static,
+    {
+        let Connection {
+            lower: lower1,
+            upper: upper1,
+        } = self;
+        let Connection {
+            lower: lower2,
+            upper: upper2,
+        } = other;
+        Connection::new(
+            move |a: &A| lower2(&lower1(a)),
+            move |c: &C| upper1(&upper2(c)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitset::BitSetLattice;
+    use crate::lattice::{BooleanAlgebra, Dual, HeytingAlgebra, MeetSemiLattice};
+    use crate::{LowerBounded, UpperBounded};
+
+    type B5 = BitSetLattice<5>;
+
+    // Fixing m, lower(a) = a ∧ m and upper(b) = m ⇒ b form an adjoint pair —
+    // exactly the adjunction behind relative pseudocomplementation.
+    fn meet_with_m_connection(m: B5) -> Connection<B5, B5> {
+        Connection::new(move |a: &B5| a.meet(&m), move |b: &B5| m.implies(b))
+    }
+
+    #[test]
+    fn adjunction_and_round_trip_laws_hold_on_samples() {
+        let m: B5 = [1, 2, 3].into_iter().collect();
+        let conn = meet_with_m_connection(m.clone());
+        let a: B5 = [0, 2].into_iter().collect();
+        let b: B5 = [1, 2, 4].into_iter().collect();
+        let c: B5 = [0, 1].into_iter().collect();
+
+        for (sa, sb) in [
+            (a.clone(), b.clone()),
+            (b, c.clone()),
+            (c, a),
+            (B5::infimum(), B5::supremum()),
+        ] {
+            assert!(conn.check_adjunction(&sa, &sb));
+            assert!(conn.round_trip_a(&sa));
+            assert!(conn.round_trip_b(&sb));
+        }
+    }
+
+    #[test]
+    fn closure_operator_is_idempotent() {
+        let m: B5 = [1, 2, 3].into_iter().collect();
+        let conn = meet_with_m_connection(m);
+        let a: B5 = [0, 2].into_iter().collect();
+        let closed = conn.closure(&a);
+        assert_eq!(conn.closure(&closed), closed);
+    }
+
+    #[test]
+    fn dual_of_dual_recovers_the_original_adjoints() {
+        let m: B5 = [1, 2, 3].into_iter().collect();
+        let conn = meet_with_m_connection(m.clone());
+        let a: B5 = [0, 2].into_iter().collect();
+        let flipped_back = conn.dual().dual();
+        assert_eq!(flipped_back.lower(&Dual(Dual(a.clone()))).0 .0, a.meet(&m));
+    }
+
+    #[test]
+    fn compose_chains_two_connections() {
+        let m: B5 = [1, 2, 3].into_iter().collect();
+        let n: B5 = [0, 1].into_iter().collect();
+        let ab = meet_with_m_connection(m.clone());
+        let bc = meet_with_m_connection(n.clone());
+        let composed = ab.compose(bc);
+        let a: B5 = [0, 1, 2].into_iter().collect();
+        assert_eq!(composed.lower(&a), a.meet(&m).meet(&n));
+    }
+}
@@ -29,6 +29,17 @@ use crate::{LowerBounded, Set, UpperBounded};
 pub trait JoinSemiLattice: Set + PartialOrd {
     /// Compute the join (least upper bound) of `self` and `other`.
     fn join(&self, other: &Self) -> Self;
+
+    /// Join `other` into `self` in place, returning whether `self` changed.
+    ///
+    /// Lets callers driving a worklist (e.g. a dataflow fixpoint) detect
+    /// stabilization without a separate equality check.
+    fn join_assign(&mut self, other: &Self) -> bool {
+        let joined = self.join(other);
+        let changed = joined != *self;
+        *self = joined;
+        changed
+    }
 }
 
 /// A meet-semilattice is a partially ordered set in which any two elements
@@ -95,3 +106,268 @@ pub trait BooleanAlgebra: DistributiveLattice + LowerBounded + UpperBounded {
     /// Return the complement of `self` (logical negation / set complement).
     fn complement(&self) -> Self;
 }
+
+/// A complete lattice extends finite joins/meets to arbitrary collections.
+///
+/// # Mathematical Definition
+/// For a bounded lattice (L, ∨, ∧, ⊥, ⊤), the supremum and infimum of a
+/// (possibly empty or infinite) collection `S ⊆ L` are the least upper bound
+/// and greatest lower bound of `S` respectively; ⊥ and ⊤ serve as the
+/// identities for the empty collection.
+///
+/// # Usage
+/// A blanket implementation is provided for any type that implements
+/// `Lattice + LowerBounded + UpperBounded`, folding the collection with
+/// `join`/`meet` starting from `⊥`/`⊤`.
+pub trait CompleteLattice: Lattice + LowerBounded + UpperBounded {
+    /// Fold an iterator with `join`, starting from `⊥` (so the empty
+    /// collection yields `⊥`).
+    fn join_all<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::infimum(), |acc, x| acc.join(&x))
+    }
+
+    /// Fold an iterator with `meet`, starting from `⊤` (so the empty
+    /// collection yields `⊤`).
+    fn meet_all<I: IntoIterator<Item = Self>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::supremum(), |acc, x| acc.meet(&x))
+    }
+
+    /// Fold a nonempty iterator with `join`, returning `None` for an empty
+    /// collection instead of relying on `⊥` as an identity.
+    fn try_join_all<I: IntoIterator<Item = Self>>(iter: I) -> Option<Self> {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
+        Some(iter.fold(first, |acc, x| acc.join(&x)))
+    }
+
+    /// Fold a nonempty iterator with `meet`, returning `None` for an empty
+    /// collection instead of relying on `⊤` as an identity.
+    fn try_meet_all<I: IntoIterator<Item = Self>>(iter: I) -> Option<Self> {
+        let mut iter = iter.into_iter();
+        let first = iter.next()?;
+        Some(iter.fold(first, |acc, x| acc.meet(&x)))
+    }
+}
+
+impl<T: Lattice + LowerBounded + UpperBounded> CompleteLattice for T {}
+
+/// A Heyting algebra is a bounded distributive lattice equipped with relative
+/// pseudocomplementation (material implication), without requiring every
+/// element to have a full complement.
+///
+/// # Mathematical Definition
+/// For a bounded distributive lattice (L, ∨, ∧, ⊥, ⊤), the relative
+/// pseudocomplement `a ⇒ b` is uniquely characterized by the adjunction, for
+/// all a, b, c ∈ L:
+///
+/// c ≤ (a ⇒ b) ⟺ (c ∧ a) ≤ b
+///
+/// Equivalently, `a ⇒ b` is the join of every c with `c ∧ a ≤ b`.
+///
+/// # Properties
+/// - Every Boolean algebra is a Heyting algebra with `a ⇒ b = ¬a ∨ b`; the
+///   blanket impl below supplies this for any `BooleanAlgebra`.
+/// - Unlike Boolean algebras, Heyting algebras need not satisfy the law of
+///   excluded middle: `neg(neg(a))` may be strictly greater than `a`.
+pub trait HeytingAlgebra: DistributiveLattice + LowerBounded {
+    /// Compute the relative pseudocomplement `a ⇒ b` (material implication).
+    fn implies(&self, other: &Self) -> Self;
+
+    /// The pseudocomplement `¬a = a ⇒ ⊥`.
+    ///
+    /// In a general Heyting algebra `neg(neg(a)) ≥ a` need not be an equality
+    /// (that equality is exactly what distinguishes Boolean algebras).
+    fn neg(&self) -> Self {
+        self.implies(&Self::infimum())
+    }
+
+    /// Biconditional `a ⟺ b = (a ⇒ b) ∧ (b ⇒ a)`.
+    fn iff(&self, other: &Self) -> Self {
+        self.implies(other).meet(&other.implies(self))
+    }
+}
+
+impl<T: BooleanAlgebra> HeytingAlgebra for T {
+    fn implies(&self, other: &Self) -> Self {
+        self.complement().join(other)
+    }
+}
+
+/// The dual of [`HeytingAlgebra`]: a bounded distributive lattice equipped
+/// with a subtraction operation instead of implication.
+///
+/// # Mathematical Definition
+/// The subtraction `a \ b` is uniquely characterized by the dual adjunction,
+/// for all a, b, c ∈ L:
+///
+/// (a \ b) ≤ c ⟺ a ≤ (b ∨ c)
+///
+/// Equivalently, `a \ b` is the meet of every c with `a ≤ b ∨ c`.
+pub trait CoHeytingAlgebra: DistributiveLattice + UpperBounded {
+    /// Compute the subtraction `a \ b`.
+    fn subtract(&self, other: &Self) -> Self;
+}
+
+/// Every Boolean algebra is a co-Heyting algebra with `a \ b = a ∧ ¬b`, the
+/// dual of the `HeytingAlgebra` blanket impl above.
+impl<T: BooleanAlgebra> CoHeytingAlgebra for T {
+    fn subtract(&self, other: &Self) -> Self {
+        self.meet(&other.complement())
+    }
+}
+
+/// A bi-Heyting algebra is simultaneously a Heyting algebra and its dual,
+/// giving both implication and subtraction over the same bounded
+/// distributive lattice.
+pub trait BiHeyting: HeytingAlgebra + CoHeytingAlgebra {}
+impl<T: HeytingAlgebra + CoHeytingAlgebra> BiHeyting for T {}
+
+/// An order-reversing wrapper that swaps join ↔ meet and ⊤ ↔ ⊥.
+///
+/// Wrapping a type in `Dual` lets an algorithm written for the meet side be
+/// reused on the join side (and vice versa) without reimplementing any
+/// structure, the same trick dataflow frameworks use with a "flipped"
+/// lattice for backward analyses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dual<T>(pub T);
+
+impl<T: PartialOrd> PartialOrd for Dual<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        other.0.partial_cmp(&self.0)
+    }
+}
+
+impl<T: Ord> Ord for Dual<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<T: MeetSemiLattice> JoinSemiLattice for Dual<T> {
+    fn join(&self, other: &Self) -> Self {
+        Dual(self.0.meet(&other.0))
+    }
+}
+
+impl<T: JoinSemiLattice> MeetSemiLattice for Dual<T> {
+    fn meet(&self, other: &Self) -> Self {
+        Dual(self.0.join(&other.0))
+    }
+}
+
+impl<T: UpperBounded> LowerBounded for Dual<T> {
+    fn infimum() -> Self {
+        Dual(T::supremum())
+    }
+}
+
+impl<T: LowerBounded> UpperBounded for Dual<T> {
+    fn supremum() -> Self {
+        Dual(T::infimum())
+    }
+}
+
+impl<T: BooleanAlgebra> BooleanAlgebra for Dual<T> {
+    fn complement(&self) -> Self {
+        Dual(self.0.complement())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitset::BitSetLattice;
+
+    type B5 = BitSetLattice<5>;
+
+    #[test]
+    fn heyting_adjunction_holds_on_sampled_inputs() {
+        let a: B5 = [0, 2].into_iter().collect();
+        let b: B5 = [1, 2, 4].into_iter().collect();
+        let imp = a.implies(&b);
+        let samples = [
+            a.clone(),
+            b.clone(),
+            a.join(&b),
+            a.meet(&b),
+            B5::infimum(),
+            B5::supremum(),
+        ];
+        for sample in samples {
+            assert_eq!(sample <= imp, sample.meet(&a) <= b);
+        }
+    }
+
+    #[test]
+    fn heyting_neg_matches_complement_on_a_boolean_algebra() {
+        let a: B5 = [0, 2].into_iter().collect();
+        assert_eq!(a.neg(), a.complement());
+    }
+
+    #[test]
+    fn co_heyting_adjunction_holds_on_sampled_inputs() {
+        let a: B5 = [0, 2].into_iter().collect();
+        let b: B5 = [1, 2, 4].into_iter().collect();
+        let sub = a.subtract(&b);
+        let samples = [
+            a.clone(),
+            b.clone(),
+            a.join(&b),
+            a.meet(&b),
+            B5::infimum(),
+            B5::supremum(),
+        ];
+        for sample in samples {
+            assert_eq!(sub <= sample, a <= b.join(&sample));
+        }
+    }
+
+    #[test]
+    fn join_all_and_meet_all_fold_a_collection() {
+        let a: B5 = [0, 2].into_iter().collect();
+        let b: B5 = [1, 2, 4].into_iter().collect();
+        let c: B5 = [0, 1].into_iter().collect();
+        assert_eq!(
+            B5::join_all([a.clone(), b.clone(), c.clone()]),
+            a.join(&b).join(&c)
+        );
+        assert_eq!(B5::meet_all([a.clone(), b.clone(), c.clone()]), a.meet(&b).meet(&c));
+    }
+
+    #[test]
+    fn join_all_and_meet_all_on_empty_collection_yield_bounds() {
+        assert_eq!(B5::join_all(std::iter::empty()), B5::infimum());
+        assert_eq!(B5::meet_all(std::iter::empty()), B5::supremum());
+    }
+
+    #[test]
+    fn try_join_all_distinguishes_empty_from_bottom() {
+        let a: B5 = [0, 2].into_iter().collect();
+        let b: B5 = [1, 2, 4].into_iter().collect();
+        assert_eq!(B5::try_join_all([a.clone(), b.clone()]), Some(a.join(&b)));
+        assert_eq!(B5::try_join_all(std::iter::empty()), None::<B5>);
+        assert_eq!(B5::try_meet_all([a.clone(), b.clone()]), Some(a.meet(&b)));
+        assert_eq!(B5::try_meet_all(std::iter::empty()), None::<B5>);
+    }
+
+    #[test]
+    fn dual_swaps_join_meet_and_bounds() {
+        let a: B5 = [0, 2].into_iter().collect();
+        let b: B5 = [1, 2, 4].into_iter().collect();
+        let (da, db) = (Dual(a.clone()), Dual(b.clone()));
+        assert_eq!(da.join(&db), Dual(a.meet(&b)));
+        assert_eq!(da.meet(&db), Dual(a.join(&b)));
+        assert_eq!(Dual::<B5>::infimum(), Dual(B5::supremum()));
+        assert_eq!(Dual::<B5>::supremum(), Dual(B5::infimum()));
+        assert_eq!(da.complement(), Dual(a.complement()));
+    }
+
+    #[test]
+    fn dual_of_dual_recovers_the_original_operations() {
+        let a: B5 = [0, 2].into_iter().collect();
+        let b: B5 = [1, 2, 4].into_iter().collect();
+        let (dda, ddb) = (Dual(Dual(a.clone())), Dual(Dual(b.clone())));
+        assert_eq!(dda.join(&ddb).0 .0, a.join(&b));
+        assert_eq!(dda.meet(&ddb).0 .0, a.meet(&b));
+    }
+}
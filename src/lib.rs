@@ -0,0 +1,13 @@
+//! Noether: composable algebraic structures for Rust.
+//!
+//! The crate is organized around small, focused traits for the usual
+//! algebraic hierarchies, each capable of standing alone or combining with
+//! the others through blanket implementations rather than inheritance.
+pub mod bitset;
+pub mod connection;
+pub mod lattice;
+pub mod ring;
+pub mod sets;
+pub mod solver;
+
+pub use sets::{LowerBounded, Set, SymmetricDifference, UpperBounded};
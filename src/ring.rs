@@ -0,0 +1,97 @@
+//! Boolean rings: the ring-theoretic view of a Boolean algebra.
+//!
+//! The [`SymmetricDifference`] docs note that `(P(X), Δ)` is an abelian
+//! group over GF(2) and that powersets form a Boolean ring under `Δ`
+//! (addition) and `∩` (multiplication). This module makes that bridge
+//! concrete: any `BooleanAlgebra + SymmetricDifference` is a `BooleanRing`
+//! for free, and the lattice operations can be recovered from the ring
+//! operations alone.
+use crate::lattice::BooleanAlgebra;
+use crate::SymmetricDifference;
+
+/// A Boolean ring: a ring in which every element is idempotent under
+/// multiplication (`a·a = a`).
+///
+/// # Mathematical Definition
+/// `(R, +, ·, 0, 1)` is a Boolean ring if, for all a ∈ R:
+///
+/// a·a = a (idempotence)
+///
+/// which forces characteristic 2 (`a + a = 0` for all a) and commutative
+/// multiplication. Every Boolean ring is isomorphic to a ring of sets under
+/// symmetric difference (addition) and intersection (multiplication) — the
+/// Stone representation.
+pub trait BooleanRing: Sized {
+    /// Ring addition (symmetric difference on sets).
+    fn add(&self, other: &Self) -> Self;
+    /// The additive identity, `0` (∅ on sets).
+    fn zero() -> Self;
+    /// Ring multiplication (intersection on sets).
+    fn mul(&self, other: &Self) -> Self;
+    /// The multiplicative identity, `1` (the universal set on sets).
+    fn one() -> Self;
+
+    /// Recover the lattice join from the ring operations:
+    /// `a ∨ b = a + b + a·b`.
+    fn lattice_join(&self, other: &Self) -> Self {
+        self.add(other).add(&self.mul(other))
+    }
+
+    /// Recover the lattice complement from the ring operations: `¬a = 1 + a`.
+    fn lattice_complement(&self) -> Self {
+        Self::one().add(self)
+    }
+}
+
+impl<T: BooleanAlgebra + SymmetricDifference> BooleanRing for T {
+    fn add(&self, other: &Self) -> Self {
+        self.sym_diff(other)
+    }
+
+    fn zero() -> Self {
+        Self::infimum()
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        self.meet(other)
+    }
+
+    fn one() -> Self {
+        Self::supremum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitset::BitSetLattice;
+    use crate::lattice::{JoinSemiLattice, MeetSemiLattice};
+    use crate::{LowerBounded, UpperBounded};
+
+    type B5 = BitSetLattice<5>;
+
+    #[test]
+    fn add_and_mul_agree_with_sym_diff_and_meet() {
+        let a: B5 = [0, 2].into_iter().collect();
+        let b: B5 = [1, 2, 4].into_iter().collect();
+        assert_eq!(a.add(&b), a.sym_diff(&b));
+        assert_eq!(a.mul(&b), a.meet(&b));
+        assert_eq!(B5::zero(), B5::infimum());
+        assert_eq!(B5::one(), B5::supremum());
+    }
+
+    #[test]
+    fn idempotent_and_characteristic_two() {
+        let a: B5 = [0, 2].into_iter().collect();
+        assert_eq!(a.mul(&a), a);
+        assert_eq!(a.add(&a), B5::zero());
+    }
+
+    #[test]
+    fn lattice_operations_round_trip_through_the_ring() {
+        let a: B5 = [0, 2].into_iter().collect();
+        let b: B5 = [1, 2, 4].into_iter().collect();
+        assert_eq!(a.lattice_join(&b), a.join(&b));
+        assert_eq!(a.lattice_complement(), a.complement());
+    }
+}
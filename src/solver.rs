@@ -0,0 +1,97 @@
+//! Dataflow-style fixpoint solver.
+//!
+//! This module treats any [`JoinSemiLattice`] + [`LowerBounded`] type as a
+//! dataflow domain and iterates a monotone transfer function over a control
+//! flow graph to a least fixed point, the same scheme MIR-style dataflow
+//! analyses use over a powerset lattice.
+use crate::lattice::JoinSemiLattice;
+use crate::LowerBounded;
+use std::collections::VecDeque;
+
+/// Iterate a monotone transfer function to a least fixed point over a graph
+/// of `successors.len()` nodes, where `successors[n]` lists the successors
+/// of node `n`. The graph may contain merges (a node with multiple
+/// predecessors) and back-edges (cycles) of the kind MIR dataflow analyses
+/// run over.
+///
+/// Node `0` (the entry node) is seeded with `init`; every other node starts
+/// at `L::infimum()`. All nodes are pushed on a worklist; popping a node
+/// recomputes its out-state via `transfer(node, in_state)` and
+/// [`join_assign`](JoinSemiLattice::join_assign)s that contribution into each
+/// successor's in-state, re-enqueuing a successor only if its state changed.
+/// The worklist empties once every node has stabilized, and the function
+/// returns each node's final in-state.
+///
+/// # Termination
+/// This only terminates if `transfer` is monotone (`a ≤ b` implies
+/// `transfer(n, a) ≤ transfer(n, b)`) and `L` satisfies the ascending chain
+/// condition (no infinite strictly increasing chain `a0 < a1 < ...`) —
+/// both of which hold for any finite powerset lattice, the canonical
+/// dataflow domain. Without them a cyclic graph could re-enqueue forever.
+pub fn fixpoint<L, F>(successors: &[Vec<usize>], init: L, mut transfer: F) -> Vec<L>
+where
+    L: JoinSemiLattice + LowerBounded + Clone,
+    F: FnMut(usize, &L) -> L,
+{
+    let nodes = successors.len();
+    if nodes == 0 {
+        return Vec::new();
+    }
+
+    let mut state: Vec<L> = (0..nodes).map(|_| L::infimum()).collect();
+    state[0] = init;
+
+    let mut worklist: VecDeque<usize> = (0..nodes).collect();
+    while let Some(node) = worklist.pop_front() {
+        let out = transfer(node, &state[node]);
+        for &successor in &successors[node] {
+            if state[successor].join_assign(&out) {
+                worklist.push_back(successor);
+            }
+        }
+    }
+
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bitset::BitSetLattice;
+
+    type B5 = BitSetLattice<5>;
+
+    // A small graph with both a merge (node 3 has predecessors 1 and 2) and
+    // a back-edge (3 -> 1), the two features a straight chain can't exercise.
+    //
+    //   0 -> 1 -> 3 -> 1  (back-edge)
+    //   0 -> 2 -> 3
+    fn diamond_with_back_edge() -> Vec<Vec<usize>> {
+        vec![vec![1, 2], vec![3], vec![3], vec![1]]
+    }
+
+    #[test]
+    fn fixpoint_merges_and_stabilizes_through_a_cycle() {
+        let entry: B5 = [0].into_iter().collect();
+        let graph = diamond_with_back_edge();
+        let states = fixpoint(&graph, entry.clone(), |node, in_state| {
+            let gen: B5 = std::iter::once(node).collect();
+            in_state.join(&gen)
+        });
+
+        assert_eq!(states[0], entry);
+        // Node 2's only predecessor is the entry node.
+        assert_eq!(states[2], entry);
+        // Nodes 1 and 3 feed each other through the back-edge, so both
+        // stabilize to the join of everything that reaches either of them.
+        let expected: B5 = [0, 1, 2, 3].into_iter().collect();
+        assert_eq!(states[1], expected);
+        assert_eq!(states[3], expected);
+    }
+
+    #[test]
+    fn fixpoint_on_an_empty_graph_returns_no_states() {
+        let states = fixpoint::<B5, _>(&[], B5::infimum(), |_, in_state| in_state.clone());
+        assert!(states.is_empty());
+    }
+}